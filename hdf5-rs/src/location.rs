@@ -1,9 +1,25 @@
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_uint, c_void};
 use std::ptr;
 
 use libhdf5_sys::{
+    h5::haddr_t,
     h5f::H5Fget_name,
     h5i::{H5Iget_file_id, H5Iget_name},
-    h5o::{H5Oget_comment, H5Oset_comment},
+    h5l::{
+        H5Ldelete, H5Literate, H5Lcreate_external, H5Lcreate_hard, H5Lcreate_soft, H5Lget_val,
+        H5Lmove, H5Lunpack_elink_val, H5L_info_t, H5L_type_t, H5L_TYPE_EXTERNAL, H5L_TYPE_HARD,
+        H5L_TYPE_SOFT,
+    },
+    h5o::{
+        H5Ocopy, H5Oget_comment, H5Oget_info, H5Oget_info_by_name, H5Oset_comment, H5O_info_t,
+        H5O_type_t, H5O_COPY_EXPAND_EXT_LINK_FLAG, H5O_COPY_EXPAND_SOFT_LINK_FLAG,
+        H5O_COPY_SHALLOW_HIERARCHY_FLAG, H5O_COPY_WITHOUT_ATTR_FLAG,
+        H5O_TYPE_DATASET, H5O_TYPE_GROUP, H5O_TYPE_NAMED_DATATYPE,
+    },
+    h5p::{H5Pclose, H5Pcreate, H5Pset_copy_object, H5P_DEFAULT, H5P_OBJECT_COPY},
+    h5::{H5_INDEX_NAME, H5_ITER_NATIVE},
 };
 
 use crate::internal_prelude::*;
@@ -16,6 +32,309 @@ def_object_class!(
     &Location::repr
 );
 
+/// The kind of object a `Location` refers to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LocationType {
+    Group,
+    Dataset,
+    NamedDatatype,
+    /// Any other object type reported by HDF5 that this crate doesn't model yet.
+    Other,
+}
+
+impl From<H5O_type_t> for LocationType {
+    fn from(tp: H5O_type_t) -> Self {
+        match tp {
+            H5O_TYPE_GROUP => Self::Group,
+            H5O_TYPE_DATASET => Self::Dataset,
+            H5O_TYPE_NAMED_DATATYPE => Self::NamedDatatype,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Opaque identifier for an HDF5 object. Two `LocationToken`s compare equal iff they were
+/// obtained (via `Location::info()`/`info_by_name()`) from handles on the same underlying
+/// object. Unlike a bare `haddr_t`, this folds in the object's file number, since addresses are
+/// only unique within a single file: two unrelated files routinely assign the same small
+/// address to their root group.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LocationToken {
+    fileno: u64,
+    addr: haddr_t,
+}
+
+impl LocationToken {
+    /// Returns the object's address within its file. On its own this is only unique within
+    /// that one file (use the token's `Eq` impl, not this value alone, to test whether two
+    /// `Location` handles refer to the same object).
+    pub fn addr(&self) -> haddr_t {
+        self.addr
+    }
+
+    /// Returns the number of the file containing the object, as reported by `H5Oget_info`.
+    pub fn fileno(&self) -> u64 {
+        self.fileno
+    }
+}
+
+/// Metadata describing an HDF5 object, as returned by `Location::info()`.
+#[derive(Clone, Copy, Debug)]
+pub struct LocationInfo {
+    /// Type of the object (group, dataset, or named datatype).
+    pub loc_type: LocationType,
+    /// Token identifying the underlying object; two `Location` handles refer to the same
+    /// object iff their tokens compare equal.
+    pub token: LocationToken,
+    /// Number of hard links to the object.
+    pub rc: u32,
+    /// Number of attributes attached to the object.
+    pub num_attrs: u64,
+    /// Last access time, in seconds since the Unix epoch.
+    pub access_time: i64,
+    /// Last modification time, in seconds since the Unix epoch.
+    pub modification_time: i64,
+    /// Last metadata change time, in seconds since the Unix epoch.
+    pub change_time: i64,
+    /// Creation ("birth") time, in seconds since the Unix epoch.
+    pub birth_time: i64,
+}
+
+impl From<H5O_info_t> for LocationInfo {
+    fn from(info: H5O_info_t) -> Self {
+        Self {
+            loc_type: info.type_.into(),
+            token: LocationToken { fileno: info.fileno as u64, addr: info.addr },
+            rc: info.rc,
+            num_attrs: info.num_attrs,
+            access_time: info.atime,
+            modification_time: info.mtime,
+            change_time: info.ctime,
+            birth_time: info.btime,
+        }
+    }
+}
+
+fn loc_info(loc_id: hid_t) -> Result<LocationInfo> {
+    h5lock!({
+        let mut info: H5O_info_t = unsafe { mem::zeroed() };
+        h5call!(H5Oget_info(loc_id, &mut info as *mut _)).map(|_| info.into())
+    })
+}
+
+fn loc_info_by_name(loc_id: hid_t, name: &str) -> Result<LocationInfo> {
+    let name = to_cstring(name)?;
+    h5lock!({
+        let mut info: H5O_info_t = unsafe { mem::zeroed() };
+        h5call!(H5Oget_info_by_name(
+            loc_id,
+            name.as_ptr(),
+            &mut info as *mut _,
+            H5P_DEFAULT
+        ))
+        .map(|_| info.into())
+    })
+}
+
+/// The kind of link an entry in a location's link table represents.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LinkType {
+    Hard,
+    Soft,
+    External,
+    /// Any other link type reported by HDF5 (e.g. a user-defined link) that this crate doesn't
+    /// model yet.
+    Other,
+}
+
+impl From<H5L_type_t> for LinkType {
+    fn from(tp: H5L_type_t) -> Self {
+        match tp {
+            H5L_TYPE_HARD => Self::Hard,
+            H5L_TYPE_SOFT => Self::Soft,
+            H5L_TYPE_EXTERNAL => Self::External,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// A single entry in a location's link table, as returned by `Location::links()`.
+#[derive(Clone, Debug)]
+pub struct LinkInfo {
+    /// Name of the link within its parent location.
+    pub name: String,
+    /// Type of the link (hard, soft, or external).
+    pub link_type: LinkType,
+    /// For soft links, the target path; for external links, the path within the target file.
+    pub target: Option<String>,
+    /// For external links, the name of the target file.
+    pub external_filename: Option<String>,
+}
+
+fn soft_link_target(loc_id: hid_t, name: &CStr, val_size: usize) -> Option<String> {
+    let mut buf = vec![0u8; val_size];
+    let ok = unsafe {
+        H5Lget_val(loc_id, name.as_ptr(), buf.as_mut_ptr() as *mut _, val_size, H5P_DEFAULT)
+    } >= 0;
+    if !ok {
+        return None;
+    }
+    CStr::from_bytes_with_nul(&buf).ok().map(|s| s.to_string_lossy().into_owned())
+}
+
+fn external_link_target(loc_id: hid_t, name: &CStr, val_size: usize) -> Option<(String, String)> {
+    let mut buf = vec![0u8; val_size];
+    let ok = unsafe {
+        H5Lget_val(loc_id, name.as_ptr(), buf.as_mut_ptr() as *mut _, val_size, H5P_DEFAULT)
+    } >= 0;
+    if !ok {
+        return None;
+    }
+    let mut flags: u32 = 0;
+    let mut filename: *const c_char = ptr::null();
+    let mut obj_path: *const c_char = ptr::null();
+    let ok = unsafe {
+        H5Lunpack_elink_val(
+            buf.as_ptr() as *const _,
+            val_size as _,
+            &mut flags,
+            &mut filename,
+            &mut obj_path,
+        )
+    } >= 0;
+    if !ok || filename.is_null() || obj_path.is_null() {
+        return None;
+    }
+    let filename = unsafe { CStr::from_ptr(filename) }.to_string_lossy().into_owned();
+    let obj_path = unsafe { CStr::from_ptr(obj_path) }.to_string_lossy().into_owned();
+    Some((filename, obj_path))
+}
+
+/// Reads the `val_size` arm of `H5L_info_t::u`, the C union that holds either the address of
+/// a hard link's target (`address`) or the length of a soft/external link's encoded value
+/// (`val_size`). Only meaningful when the link is soft or external; isolated here because
+/// reading a union field is unsafe (the union itself doesn't track which variant is active).
+fn link_val_size(info: &H5L_info_t) -> usize {
+    unsafe { info.u.val_size as usize }
+}
+
+extern "C" fn links_callback(
+    loc_id: hid_t,
+    name: *const c_char,
+    info: *const H5L_info_t,
+    op_data: *mut c_void,
+) -> herr_t {
+    let out = unsafe { &mut *(op_data as *mut Vec<LinkInfo>) };
+    let c_name = unsafe { CStr::from_ptr(name) };
+    let info = unsafe { &*info };
+    let link_type = info.type_.into();
+    let (target, external_filename) = match link_type {
+        LinkType::Soft => (soft_link_target(loc_id, c_name, link_val_size(info)), None),
+        LinkType::External => match external_link_target(loc_id, c_name, link_val_size(info)) {
+            Some((filename, obj_path)) => (Some(obj_path), Some(filename)),
+            None => (None, None),
+        },
+        _ => (None, None),
+    };
+    out.push(LinkInfo {
+        name: c_name.to_string_lossy().into_owned(),
+        link_type,
+        target,
+        external_filename,
+    });
+    0
+}
+
+/// Options controlling how `Location::copy_to()` (or `CopyOptions::copy()`) copies an object,
+/// wrapping the flags accepted by `H5Ocopy`'s object copy property list.
+///
+/// Note: there is no option here for preserving or refreshing creation order during the copy.
+/// `H5Ocopy`'s object copy property list has no flag for it (`H5O_COPY_PRESERVE_NULL_FLAG`
+/// preserves NULL object-header messages, which is a different thing), so that part of the
+/// original request isn't implemented.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CopyOptions {
+    shallow: bool,
+    expand_soft_links: bool,
+    expand_external_links: bool,
+    without_attributes: bool,
+}
+
+impl CopyOptions {
+    /// Creates a new set of options, all defaulting to the behavior of a plain `H5Ocopy` call:
+    /// a full recursive copy of a group's contents, expanding neither soft nor external links,
+    /// and preserving attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// If `true`, only the immediate members of a group are copied, rather than the whole
+    /// subtree. Has no effect when copying a dataset or named datatype.
+    pub fn shallow(&mut self, shallow: bool) -> &mut Self {
+        self.shallow = shallow;
+        self
+    }
+
+    /// If `true`, soft links encountered during the copy are expanded: the objects they point
+    /// to are copied in place of the link, rather than the link itself.
+    pub fn expand_soft_links(&mut self, expand: bool) -> &mut Self {
+        self.expand_soft_links = expand;
+        self
+    }
+
+    /// If `true`, external links encountered during the copy are expanded: the objects they
+    /// point to are copied in place of the link, rather than the link itself.
+    pub fn expand_external_links(&mut self, expand: bool) -> &mut Self {
+        self.expand_external_links = expand;
+        self
+    }
+
+    /// If `true`, the copy does not include the attributes of the copied object(s).
+    pub fn without_attributes(&mut self, without: bool) -> &mut Self {
+        self.without_attributes = without;
+        self
+    }
+
+    fn flags(&self) -> c_uint {
+        let mut flags = 0;
+        if self.shallow {
+            flags |= H5O_COPY_SHALLOW_HIERARCHY_FLAG;
+        }
+        if self.expand_soft_links {
+            flags |= H5O_COPY_EXPAND_SOFT_LINK_FLAG;
+        }
+        if self.expand_external_links {
+            flags |= H5O_COPY_EXPAND_EXT_LINK_FLAG;
+        }
+        if self.without_attributes {
+            flags |= H5O_COPY_WITHOUT_ATTR_FLAG;
+        }
+        flags as _
+    }
+
+    /// Copies `src` into `dst` under `dst_name`, per the options configured on `self`.
+    pub fn copy(&self, src: &Location, dst: &Location, dst_name: &str) -> Result<()> {
+        let src_name = to_cstring(".")?;
+        let dst_name = to_cstring(dst_name)?;
+        h5lock!({
+            let ocpypl_id = h5try!(H5Pcreate(*H5P_OBJECT_COPY));
+            let result = h5call!(H5Pset_copy_object(ocpypl_id, self.flags()))
+                .and_then(|_| {
+                    h5call!(H5Ocopy(
+                        src.id(),
+                        src_name.as_ptr(),
+                        dst.id(),
+                        dst_name.as_ptr(),
+                        ocpypl_id,
+                        H5P_DEFAULT
+                    ))
+                });
+            h5call!(H5Pclose(ocpypl_id)).ok();
+            result.and(Ok(()))
+        })
+    }
+}
+
 impl Location {
     /// Returns the name of the object within the file, or empty string if the object doesn't
     /// have a name (e.g., an anonymous dataset).
@@ -55,6 +374,125 @@ impl Location {
         h5call!(H5Oset_comment(self.id(), ptr::null_mut())).and(Ok(()))
     }
 
+    /// Returns the metadata for the object: its type, hard-link count, attribute count,
+    /// timestamps, and a token that can be used to tell whether two locations refer to the
+    /// same underlying object.
+    pub fn info(&self) -> Result<LocationInfo> {
+        loc_info(self.id())
+    }
+
+    /// Returns the metadata for the object named `name`, relative to this location, without
+    /// opening it.
+    pub fn info_by_name(&self, name: &str) -> Result<LocationInfo> {
+        loc_info_by_name(self.id(), name)
+    }
+
+    /// Creates a new hard link to `target` named `link_name`, within this location.
+    pub fn create_hard_link(&self, target: &Location, link_name: &str) -> Result<()> {
+        let cur_name = to_cstring(".")?;
+        let link_name = to_cstring(link_name)?;
+        h5call!(H5Lcreate_hard(
+            target.id(),
+            cur_name.as_ptr(),
+            self.id(),
+            link_name.as_ptr(),
+            H5P_DEFAULT,
+            H5P_DEFAULT
+        ))
+        .and(Ok(()))
+    }
+
+    /// Creates a new soft link named `link_name`, within this location, pointing at
+    /// `target_path` (which need not exist, and is resolved when it's followed: an absolute
+    /// path from the root of the file, a relative path from the group holding the link).
+    pub fn create_soft_link(&self, target_path: &str, link_name: &str) -> Result<()> {
+        let target_path = to_cstring(target_path)?;
+        let link_name = to_cstring(link_name)?;
+        h5call!(H5Lcreate_soft(
+            target_path.as_ptr(),
+            self.id(),
+            link_name.as_ptr(),
+            H5P_DEFAULT,
+            H5P_DEFAULT
+        ))
+        .and(Ok(()))
+    }
+
+    /// Creates a new external link named `link_name`, within this location, pointing at
+    /// `target_path` within `file`.
+    pub fn create_external_link(
+        &self, file: &str, target_path: &str, link_name: &str,
+    ) -> Result<()> {
+        let file = to_cstring(file)?;
+        let target_path = to_cstring(target_path)?;
+        let link_name = to_cstring(link_name)?;
+        h5call!(H5Lcreate_external(
+            file.as_ptr(),
+            target_path.as_ptr(),
+            self.id(),
+            link_name.as_ptr(),
+            H5P_DEFAULT,
+            H5P_DEFAULT
+        ))
+        .and(Ok(()))
+    }
+
+    /// Removes the link named `name` from this location. If it was the last link to the
+    /// underlying object, the object itself is deleted once nothing else references it.
+    pub fn delete_link(&self, name: &str) -> Result<()> {
+        let name = to_cstring(name)?;
+        h5call!(H5Ldelete(self.id(), name.as_ptr(), H5P_DEFAULT)).and(Ok(()))
+    }
+
+    /// Moves (and/or renames) the link named `src_name` in this location to `dst_name` in
+    /// `dst`, which may be a different location. A hard link can only be moved within the same
+    /// file; HDF5 returns an error when `dst` is in a different file.
+    pub fn move_link(&self, src_name: &str, dst: &Location, dst_name: &str) -> Result<()> {
+        let src_name = to_cstring(src_name)?;
+        let dst_name = to_cstring(dst_name)?;
+        h5call!(H5Lmove(
+            self.id(),
+            src_name.as_ptr(),
+            dst.id(),
+            dst_name.as_ptr(),
+            H5P_DEFAULT,
+            H5P_DEFAULT
+        ))
+        .and(Ok(()))
+    }
+
+    /// Renames the link named `name` to `new_name`, within this location.
+    pub fn relink(&self, name: &str, new_name: &str) -> Result<()> {
+        self.move_link(name, self, new_name)
+    }
+
+    /// Returns an iterator over the links directly contained in this location (does not
+    /// recurse into subgroups).
+    ///
+    /// `H5Literate` only supports visiting links through a callback, so there's no way to pull
+    /// entries from HDF5 one at a time; this collects all entries up front and hands back an
+    /// iterator over that buffer rather than a stream backed by further library calls.
+    pub fn links(&self) -> Result<impl Iterator<Item = LinkInfo>> {
+        let mut out: Vec<LinkInfo> = Vec::new();
+        let mut idx: u64 = 0;
+        h5call!(H5Literate(
+            self.id(),
+            H5_INDEX_NAME,
+            H5_ITER_NATIVE,
+            &mut idx as *mut _,
+            Some(links_callback),
+            &mut out as *mut _ as *mut _,
+        ))
+        .and(Ok(out.into_iter()))
+    }
+
+    /// Copies this object into `dst` under `dst_name`, using the default `CopyOptions` (a full
+    /// recursive copy that preserves attributes). Use `CopyOptions::copy()` directly for
+    /// control over shallow copies, link expansion, or attribute preservation.
+    pub fn copy_to(&self, dst: &Location, dst_name: &str) -> Result<()> {
+        CopyOptions::new().copy(self, dst, dst_name)
+    }
+
     fn repr(&self) -> String {
         format!("\"{}\"", self.name())
     }
@@ -95,4 +533,105 @@ pub mod tests {
             assert!(file.comment().is_none());
         })
     }
+
+    #[test]
+    pub fn test_info() {
+        with_tmp_file(|file| {
+            let info = file.info().unwrap();
+            assert_eq!(info.loc_type, LocationType::Group);
+            assert_eq!(info.num_attrs, 0);
+            assert!(info.rc >= 1);
+        })
+    }
+
+    #[test]
+    pub fn test_info_by_name() {
+        with_tmp_file(|file| {
+            file.create_group("foo").unwrap();
+            let info = file.info_by_name("foo").unwrap();
+            assert_eq!(info.loc_type, LocationType::Group);
+        })
+    }
+
+    #[test]
+    pub fn test_info_token_identifies_object() {
+        with_tmp_file(|file| {
+            assert_eq!(file.info().unwrap().token, file.info().unwrap().token);
+        })
+    }
+
+    #[test]
+    pub fn test_hard_link() {
+        with_tmp_file(|file| {
+            let group = file.create_group("foo").unwrap();
+            file.create_hard_link(&group, "bar").unwrap();
+            assert_eq!(file.info_by_name("bar").unwrap().token, group.info().unwrap().token);
+        })
+    }
+
+    #[test]
+    pub fn test_soft_link() {
+        with_tmp_file(|file| {
+            file.create_group("foo").unwrap();
+            file.create_soft_link("/foo", "bar").unwrap();
+            let link = file.links().unwrap().find(|l| l.name == "bar").unwrap();
+            assert_eq!(link.link_type, LinkType::Soft);
+            assert_eq!(link.target.as_deref(), Some("/foo"));
+        })
+    }
+
+    #[test]
+    pub fn test_delete_and_move_link() {
+        with_tmp_file(|file| {
+            file.create_group("foo").unwrap();
+            file.relink("foo", "bar").unwrap();
+            assert!(file.info_by_name("bar").is_ok());
+            file.delete_link("bar").unwrap();
+            assert!(file.info_by_name("bar").is_err());
+        })
+    }
+
+    #[test]
+    pub fn test_links_lists_hard_links() {
+        with_tmp_file(|file| {
+            file.create_group("foo").unwrap();
+            file.create_group("baz").unwrap();
+            let mut names: Vec<_> = file.links().unwrap().into_iter().map(|l| l.name).collect();
+            names.sort();
+            assert_eq!(names, vec!["baz".to_string(), "foo".to_string()]);
+        })
+    }
+
+    #[test]
+    pub fn test_copy_dataset() {
+        with_tmp_file(|file| {
+            let src = file.new_dataset::<i32>().create("src", (3,)).unwrap();
+            src.copy_to(&file, "dst").unwrap();
+            assert_eq!(file.info_by_name("dst").unwrap().loc_type, LocationType::Dataset);
+        })
+    }
+
+    #[test]
+    pub fn test_copy_group_recursive() {
+        with_tmp_file(|file| {
+            let src = file.create_group("src").unwrap();
+            src.create_group("child").unwrap();
+            src.copy_to(&file, "dst").unwrap();
+            assert!(file.info_by_name("dst/child").is_ok());
+        })
+    }
+
+    #[test]
+    pub fn test_copy_with_and_without_attributes() {
+        with_tmp_file(|file| {
+            let src = file.create_group("src").unwrap();
+            src.new_attr::<i32>().create("attr").unwrap();
+
+            CopyOptions::new().copy(&src, &file, "with_attrs").unwrap();
+            assert_eq!(file.group("with_attrs").unwrap().info().unwrap().num_attrs, 1);
+
+            CopyOptions::new().without_attributes(true).copy(&src, &file, "without_attrs").unwrap();
+            assert_eq!(file.group("without_attrs").unwrap().info().unwrap().num_attrs, 0);
+        })
+    }
 }